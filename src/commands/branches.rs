@@ -0,0 +1,50 @@
+use crate::git::GitRepo;
+use crate::ui::format_time;
+use crate::worktree::list_worktrees;
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+pub fn execute(repo: &GitRepo) -> Result<()> {
+    let branches = repo.branches()?;
+    let worktrees = list_worktrees(repo)?;
+
+    let checked_out: std::collections::HashSet<&str> = worktrees
+        .iter()
+        .filter_map(|wt| wt.branch_short.as_deref())
+        .collect();
+
+    let available: Vec<&crate::git::Branch> = branches
+        .iter()
+        .filter(|b| !checked_out.contains(b.name.as_str()))
+        .collect();
+
+    if available.is_empty() {
+        println!("No branches without a worktree.");
+        return Ok(());
+    }
+
+    let max_name_len = available
+        .iter()
+        .map(|b| b.name.len())
+        .max()
+        .unwrap_or(6)
+        .max(6);
+
+    println!(
+        "  {:width$}  {}",
+        "BRANCH".dimmed(),
+        "AGE".dimmed(),
+        width = max_name_len
+    );
+
+    for branch in available {
+        println!(
+            "  {:width$}  {}",
+            branch.name,
+            format_time(branch.last_commit_seconds),
+            width = max_name_len
+        );
+    }
+
+    Ok(())
+}
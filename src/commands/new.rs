@@ -1,13 +1,16 @@
 use crate::config::Config;
 use crate::git::GitRepo;
-use crate::ui::{print_info, print_success};
+use crate::ui::{format_time, print_info, print_success};
 use crate::worktree::{list_worktrees, slug_from_branch};
 use anyhow::{bail, Context, Result};
+use console::Term;
+use dialoguer::FuzzySelect;
+use is_terminal::IsTerminal;
 use std::path::PathBuf;
 use std::process::Command;
 
 pub struct NewOptions {
-    pub name: String,
+    pub name: Option<String>,
     pub from: Option<String>,
     pub path: Option<PathBuf>,
     pub print_path: bool,
@@ -19,7 +22,11 @@ pub struct NewOptions {
 pub fn execute(repo: &GitRepo, opts: NewOptions) -> Result<()> {
     let config = Config::load(repo)?;
 
-    let branch_name = &opts.name;
+    let name = match &opts.name {
+        Some(name) => name.clone(),
+        None => pick_branch_name(repo)?,
+    };
+    let branch_name = &name;
     let slug = slug_from_branch(branch_name);
 
     let worktree_path = opts
@@ -150,6 +157,45 @@ pub fn execute(repo: &GitRepo, opts: NewOptions) -> Result<()> {
     Ok(())
 }
 
+/// Interactively pick a branch to check out, most-recently-committed first.
+fn pick_branch_name(repo: &GitRepo) -> Result<String> {
+    if !std::io::stdin().is_terminal() {
+        bail!("No branch name given. Pass one explicitly in non-interactive mode.");
+    }
+
+    let worktrees = list_worktrees(repo)?;
+    let checked_out: std::collections::HashSet<&str> = worktrees
+        .iter()
+        .filter_map(|wt| wt.branch_short.as_deref())
+        .collect();
+
+    let branches = repo.branches()?;
+    let available: Vec<&crate::git::Branch> = branches
+        .iter()
+        .filter(|b| !checked_out.contains(b.name.as_str()))
+        .collect();
+
+    if available.is_empty() {
+        bail!("No branches without a worktree. Pass a new branch name to create one.");
+    }
+
+    let items: Vec<String> = available
+        .iter()
+        .map(|b| format!("{:30}  {}", b.name, format_time(b.last_commit_seconds)))
+        .collect();
+
+    let selection = FuzzySelect::new()
+        .with_prompt("Select branch")
+        .items(&items)
+        .default(0)
+        .interact_on_opt(&Term::stderr())?;
+
+    match selection {
+        Some(idx) => Ok(available[idx].name.clone()),
+        None => std::process::exit(130),
+    }
+}
+
 fn get_upstream(repo: &GitRepo, branch: &str) -> Option<String> {
     let output = Command::new("git")
         .current_dir(&repo.root)
@@ -1,29 +1,43 @@
 use crate::git::GitRepo;
-use crate::status::get_all_statuses;
-use crate::ui::{print_error, UiOptions};
-use crate::worktree::list_worktrees;
+use crate::status::{get_all_statuses_cached, WorktreeStatus, DEFAULT_STATUS_CACHE_TTL_SECS};
+use crate::ui::{print_error, JsonWorktree, UiOptions};
+use crate::worktree::{list_worktrees, Worktree};
 use anyhow::Result;
 use console::Term;
 use dialoguer::FuzzySelect;
 use is_terminal::IsTerminal;
 
-pub fn execute(repo: &GitRepo, _opts: &UiOptions) -> Result<()> {
-    if !std::io::stdin().is_terminal() {
-        print_error(
-            "Cannot run interactive picker in non-TTY environment",
-            Some("Use `git workty go <name>` for non-interactive selection."),
-        );
-        std::process::exit(1);
-    }
-
+pub fn execute(repo: &GitRepo, opts: &UiOptions) -> Result<()> {
     let worktrees = list_worktrees(repo)?;
     if worktrees.is_empty() {
+        if opts.json {
+            println!("[]");
+            return Ok(());
+        }
         print_error("No worktrees found", None);
         std::process::exit(1);
     }
 
     // Get status for richer display
-    let statuses = get_all_statuses(repo, &worktrees);
+    let statuses = get_all_statuses_cached(
+        repo,
+        &worktrees,
+        DEFAULT_STATUS_CACHE_TTL_SECS,
+        opts.refresh,
+    );
+
+    if opts.json {
+        print_statuses_json(&statuses);
+        return Ok(());
+    }
+
+    if !std::io::stdin().is_terminal() {
+        print_error(
+            "Cannot run interactive picker in non-TTY environment",
+            Some("Use `--json` for scripting or `git workty go <name>` for non-interactive selection."),
+        );
+        std::process::exit(1);
+    }
 
     // Find max name length for alignment
     let max_name_len = statuses
@@ -38,8 +52,10 @@ pub fn execute(repo: &GitRepo, _opts: &UiOptions) -> Result<()> {
             let name = format!("{:width$}", wt.name(), width = max_name_len);
 
             // Dirty indicator
-            let dirty = if status.dirty_count > 0 {
-                format!("*{}", status.dirty_count)
+            let dirty = if status.has_conflicts() {
+                format!("!{}", status.conflicted)
+            } else if status.dirty_count > 0 {
+                format!("*{}+{}", status.unstaged + status.untracked, status.staged)
             } else {
                 " ".to_string()
             };
@@ -54,7 +70,19 @@ pub fn execute(repo: &GitRepo, _opts: &UiOptions) -> Result<()> {
                 "  ".to_string()
             };
 
-            format!("{}  {:>3}  {:>4}  {}", name, dirty, time, rebase)
+            // Stash indicator
+            let stash = if status.stash_count > 0 {
+                format!("S{}", status.stash_count)
+            } else {
+                "  ".to_string()
+            };
+
+            let describe = status.describe.as_deref().unwrap_or("-");
+
+            format!(
+                "{}  {:>3}  {:>4}  {}  {}  {}",
+                name, dirty, time, rebase, stash, describe
+            )
         })
         .collect();
 
@@ -75,6 +103,16 @@ pub fn execute(repo: &GitRepo, _opts: &UiOptions) -> Result<()> {
     }
 }
 
+fn print_statuses_json(statuses: &[(Worktree, WorktreeStatus)]) {
+    let entries: Vec<JsonWorktree> = statuses
+        .iter()
+        .map(|(wt, status)| JsonWorktree::from_status(wt, status))
+        .collect();
+
+    let json = serde_json::to_string_pretty(&entries).unwrap_or_else(|_| "[]".to_string());
+    println!("{}", json);
+}
+
 fn format_time_short(seconds: Option<i64>) -> String {
     match seconds {
         Some(s) if s < 60 => "now".to_string(),
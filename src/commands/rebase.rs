@@ -0,0 +1,176 @@
+use crate::git::GitRepo;
+use crate::status::{get_all_statuses, is_worktree_dirty};
+use crate::ui;
+use crate::worktree::{find_worktree, list_worktrees, Worktree};
+use anyhow::{bail, Context, Result};
+use rayon::prelude::*;
+
+pub struct RebaseOptions {
+    pub name: Option<String>,
+    pub all: bool,
+}
+
+enum RebaseOutcome {
+    Rebased,
+    SkippedClean,
+    SkippedDirty,
+    Conflicted,
+    Failed(String),
+}
+
+pub fn execute(repo: &GitRepo, opts: RebaseOptions) -> Result<()> {
+    if opts.name.is_none() && !opts.all {
+        bail!("Specify a worktree name or --all");
+    }
+
+    let worktrees = list_worktrees(repo)?;
+
+    let targets: Vec<Worktree> = if opts.all {
+        worktrees
+    } else {
+        let name = opts.name.as_deref().unwrap();
+        let wt = find_worktree(&worktrees, name).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Worktree '{}' not found. Use `git workty list` to see available worktrees.",
+                name
+            )
+        })?;
+        vec![wt.clone()]
+    };
+
+    let statuses = get_all_statuses(repo, &targets);
+
+    let results: Vec<(String, RebaseOutcome)> = statuses
+        .par_iter()
+        .map(|(wt, status)| {
+            let name = wt.name().to_string();
+
+            if !status.needs_rebase() {
+                return (name, RebaseOutcome::SkippedClean);
+            }
+
+            if is_worktree_dirty(wt) {
+                return (name, RebaseOutcome::SkippedDirty);
+            }
+
+            (name, rebase_worktree(wt))
+        })
+        .collect();
+
+    let mut rebased = 0;
+    let mut skipped_clean = 0;
+    let mut skipped_dirty = 0;
+    let mut conflicted = 0;
+    let mut failed = 0;
+
+    for (name, outcome) in results {
+        match outcome {
+            RebaseOutcome::Rebased => {
+                ui::print_success(&format!("{}: rebased onto main", name));
+                rebased += 1;
+            }
+            RebaseOutcome::SkippedClean => {
+                skipped_clean += 1;
+            }
+            RebaseOutcome::SkippedDirty => {
+                ui::print_warning(&format!("{}: skipped (dirty)", name));
+                skipped_dirty += 1;
+            }
+            RebaseOutcome::Conflicted => {
+                ui::print_warning(&format!(
+                    "{}: rebase conflicted, aborted and left untouched",
+                    name
+                ));
+                conflicted += 1;
+            }
+            RebaseOutcome::Failed(err) => {
+                ui::print_warning(&format!("{}: rebase failed, aborted: {}", name, err));
+                failed += 1;
+            }
+        }
+    }
+
+    println!();
+    ui::print_info(&format!(
+        "Rebased: {}, already up to date: {}, skipped (dirty): {}, conflicted: {}, failed: {}",
+        rebased, skipped_clean, skipped_dirty, conflicted, failed
+    ));
+
+    Ok(())
+}
+
+fn rebase_worktree(wt: &Worktree) -> RebaseOutcome {
+    match try_rebase_worktree(wt) {
+        Ok(outcome) => outcome,
+        Err(e) => RebaseOutcome::Failed(e.to_string()),
+    }
+}
+
+fn try_rebase_worktree(wt: &Worktree) -> Result<RebaseOutcome> {
+    let repo = git2::Repository::open(&wt.path).context("Failed to open worktree")?;
+
+    let head = repo.head().context("Failed to read HEAD")?;
+    let branch_oid = head.target().context("HEAD has no target")?;
+    let branch = repo.find_annotated_commit(branch_oid)?;
+
+    let onto_oid = repo
+        .find_reference("refs/remotes/origin/main")
+        .or_else(|_| repo.find_reference("refs/remotes/origin/master"))
+        .context("No origin/main or origin/master to rebase onto")?
+        .target()
+        .context("onto reference has no target")?;
+    let onto = repo.find_annotated_commit(onto_oid)?;
+
+    // Pass `upstream: None` so libgit2 computes merge-base(branch, onto) itself. Passing the
+    // branch's push-tracking upstream here (e.g. origin/<branch>, fully in sync after `workty
+    // new`'s `git push -u`) would make the replay range merge-base(branch, that upstream)..branch,
+    // which is empty when the branch is fully pushed - `rebase.finish()` would then just reset
+    // the branch straight to `onto`, discarding every commit on it.
+    let mut rebase_opts = git2::RebaseOptions::new();
+    let mut rebase = repo.rebase(Some(&branch), None, Some(&onto), Some(&mut rebase_opts))?;
+
+    // From here on, a rebase is in progress on disk (`.git/rebase-merge`). Route every error
+    // path through `run_rebase_operations` and abort on *any* failure it reports - not just
+    // actual conflicts - so a dirty or conflicting worktree never ends up half-rebased.
+    match run_rebase_operations(&repo, &mut rebase) {
+        Ok(RebaseOutcome::Conflicted) => {
+            let _ = rebase.abort();
+            Ok(RebaseOutcome::Conflicted)
+        }
+        Ok(outcome) => Ok(outcome),
+        Err(e) => {
+            let _ = rebase.abort();
+            Err(e)
+        }
+    }
+}
+
+fn run_rebase_operations(
+    repo: &git2::Repository,
+    rebase: &mut git2::Rebase,
+) -> Result<RebaseOutcome> {
+    while let Some(operation) = rebase.next() {
+        let operation = operation?;
+
+        if repo.index()?.has_conflicts() {
+            return Ok(RebaseOutcome::Conflicted);
+        }
+
+        let original = repo.find_commit(operation.id())?;
+        if let Err(e) = rebase.commit(Some(&original.author()), &original.committer(), None) {
+            // Nothing to commit (operation was a no-op) - keep going.
+            if e.code() != git2::ErrorCode::Applied {
+                return Ok(RebaseOutcome::Conflicted);
+            }
+        }
+    }
+
+    if repo.index()?.has_conflicts() {
+        return Ok(RebaseOutcome::Conflicted);
+    }
+
+    let sig = repo.signature().context("No signature configured")?;
+    rebase.finish(Some(&sig))?;
+
+    Ok(RebaseOutcome::Rebased)
+}
@@ -5,9 +5,20 @@ use crate::worktree::list_worktrees;
 use anyhow::{Context, Result};
 use std::process::Command;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncStrategy {
+    Rebase,
+    Merge,
+    FastForwardOnly,
+}
+
 pub struct SyncOptions {
     pub dry_run: bool,
     pub fetch: bool,
+    pub strategy: SyncStrategy,
+    /// On conflict, leave the worktree in its in-progress rebase/merge state instead of
+    /// aborting, so the user can `cd` in and resolve it manually.
+    pub keep_conflicts: bool,
 }
 
 pub fn execute(repo: &GitRepo, opts: SyncOptions) -> Result<()> {
@@ -32,6 +43,7 @@ pub fn execute(repo: &GitRepo, opts: SyncOptions) -> Result<()> {
     let mut skipped_dirty = 0;
     let mut skipped_no_upstream = 0;
     let mut failed = 0;
+    let mut needs_resolution = 0;
 
     for (wt, status) in &statuses {
         // Skip main worktree (usually you don't want to auto-rebase main)
@@ -45,10 +57,13 @@ pub fn execute(repo: &GitRepo, opts: SyncOptions) -> Result<()> {
         }
 
         // Skip if no upstream
-        if status.upstream.is_none() {
-            skipped_no_upstream += 1;
-            continue;
-        }
+        let upstream = match &status.upstream {
+            Some(upstream) => upstream,
+            None => {
+                skipped_no_upstream += 1;
+                continue;
+            }
+        };
 
         // Skip if nothing to sync
         let behind = status.behind.unwrap_or(0);
@@ -58,6 +73,13 @@ pub fn execute(repo: &GitRepo, opts: SyncOptions) -> Result<()> {
 
         let branch_name = wt.name();
 
+        // FastForwardOnly never conflicts, but it can't proceed if the branch has also
+        // diverged (has its own commits not on upstream).
+        if opts.strategy == SyncStrategy::FastForwardOnly && status.ahead.unwrap_or(0) > 0 {
+            ui::print_info(&format!("{}: skipped (diverged, not a fast-forward)", branch_name));
+            continue;
+        }
+
         // Skip if dirty
         if is_worktree_dirty(wt) {
             if !opts.dry_run {
@@ -69,33 +91,48 @@ pub fn execute(repo: &GitRepo, opts: SyncOptions) -> Result<()> {
 
         if opts.dry_run {
             ui::print_info(&format!(
-                "{}: would rebase ({} commits behind)",
-                branch_name, behind
+                "{}: would {} ({} commits behind)",
+                branch_name,
+                opts.strategy.verb(),
+                behind
             ));
             synced += 1;
             continue;
         }
 
-        // Perform the rebase
-        ui::print_info(&format!("{}: rebasing...", branch_name));
+        ui::print_info(&format!("{}: {}...", branch_name, opts.strategy.verb_ing()));
 
         let output = Command::new("git")
             .current_dir(&wt.path)
-            .args(["rebase"])
+            .args(opts.strategy.args(upstream))
             .output()
-            .context("Failed to run git rebase")?;
+            .context("Failed to run git")?;
 
         if output.status.success() {
-            ui::print_success(&format!("{}: rebased", branch_name));
+            ui::print_success(&format!("{}: {}", branch_name, opts.strategy.past_tense()));
             synced += 1;
-        } else {
-            // Abort the failed rebase
-            let _ = Command::new("git")
-                .current_dir(&wt.path)
-                .args(["rebase", "--abort"])
-                .output();
+            continue;
+        }
 
-            ui::print_warning(&format!("{}: rebase failed, aborted", branch_name));
+        let conflicted_paths = count_conflicted_paths(&wt.path);
+
+        if opts.keep_conflicts && conflicted_paths > 0 {
+            ui::print_warning(&format!(
+                "{}: stopped on {} conflicting path(s), left for manual resolution",
+                branch_name, conflicted_paths
+            ));
+            needs_resolution += 1;
+        } else {
+            abort_in_progress(&wt.path, opts.strategy);
+            if opts.strategy == SyncStrategy::FastForwardOnly {
+                ui::print_warning(&format!("{}: fast-forward failed (not a fast-forward)", branch_name));
+            } else {
+                ui::print_warning(&format!(
+                    "{}: {} failed, aborted",
+                    branch_name,
+                    opts.strategy.verb()
+                ));
+            }
             failed += 1;
         }
     }
@@ -106,10 +143,70 @@ pub fn execute(repo: &GitRepo, opts: SyncOptions) -> Result<()> {
         ui::print_info(&format!("Would sync {} worktree(s)", synced));
     } else {
         ui::print_info(&format!(
-            "Synced: {}, Skipped (dirty): {}, Skipped (no upstream): {}, Failed: {}",
-            synced, skipped_dirty, skipped_no_upstream, failed
+            "Synced: {}, Skipped (dirty): {}, Skipped (no upstream): {}, Failed: {}, Need conflict resolution: {}",
+            synced, skipped_dirty, skipped_no_upstream, failed, needs_resolution
         ));
     }
 
     Ok(())
 }
+
+impl SyncStrategy {
+    fn args<'a>(&self, upstream: &'a str) -> Vec<&'a str> {
+        match self {
+            SyncStrategy::Rebase => vec!["rebase", upstream],
+            SyncStrategy::Merge => vec!["merge", upstream],
+            SyncStrategy::FastForwardOnly => vec!["merge", "--ff-only", upstream],
+        }
+    }
+
+    fn verb(&self) -> &'static str {
+        match self {
+            SyncStrategy::Rebase => "rebase",
+            SyncStrategy::Merge => "merge",
+            SyncStrategy::FastForwardOnly => "fast-forward",
+        }
+    }
+
+    fn verb_ing(&self) -> &'static str {
+        match self {
+            SyncStrategy::Rebase => "rebasing",
+            SyncStrategy::Merge => "merging",
+            SyncStrategy::FastForwardOnly => "fast-forwarding",
+        }
+    }
+
+    fn past_tense(&self) -> &'static str {
+        match self {
+            SyncStrategy::Rebase => "rebased",
+            SyncStrategy::Merge => "merged",
+            SyncStrategy::FastForwardOnly => "fast-forwarded",
+        }
+    }
+}
+
+fn abort_in_progress(worktree_path: &std::path::Path, strategy: SyncStrategy) {
+    let args: &[&str] = match strategy {
+        SyncStrategy::Rebase => &["rebase", "--abort"],
+        SyncStrategy::Merge => &["merge", "--abort"],
+        // A failed --ff-only merge never starts an in-progress merge, nothing to abort.
+        SyncStrategy::FastForwardOnly => return,
+    };
+
+    let _ = Command::new("git").current_dir(worktree_path).args(args).output();
+}
+
+fn count_conflicted_paths(worktree_path: &std::path::Path) -> usize {
+    let output = Command::new("git")
+        .current_dir(worktree_path)
+        .args(["diff", "--name-only", "--diff-filter=U"])
+        .output();
+
+    match output {
+        Ok(o) if o.status.success() => String::from_utf8_lossy(&o.stdout)
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .count(),
+        _ => 0,
+    }
+}
@@ -10,6 +10,12 @@ pub struct GitRepo {
     pub common_dir: PathBuf,
 }
 
+/// A local branch, with how long ago its tip was committed (for recency sorting).
+pub struct Branch {
+    pub name: String,
+    pub last_commit_seconds: Option<i64>,
+}
+
 impl GitRepo {
     pub fn discover(start_path: Option<&Path>) -> Result<Self> {
         let working_directory = start_path
@@ -72,6 +78,36 @@ impl GitRepo {
         exists
     }
 
+    /// Returns every local branch, most recently committed first.
+    pub fn branches(&self) -> Result<Vec<Branch>> {
+        let repo = self.repo.lock().unwrap();
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+
+        let mut branches: Vec<Branch> = repo
+            .branches(Some(git2::BranchType::Local))
+            .context("Failed to list branches")?
+            .filter_map(|entry| {
+                let (branch, _) = entry.ok()?;
+                let name = branch.name().ok().flatten()?.to_string();
+                let last_commit_seconds = branch
+                    .get()
+                    .target()
+                    .and_then(|oid| repo.find_commit(oid).ok())
+                    .map(|commit| now - commit.time().seconds());
+                Some(Branch {
+                    name,
+                    last_commit_seconds,
+                })
+            })
+            .collect();
+
+        branches.sort_by_key(|b| b.last_commit_seconds.unwrap_or(i64::MAX));
+        Ok(branches)
+    }
+
     pub fn is_merged(&self, branch: &str, base: &str) -> Result<bool> {
         let repo = self.repo.lock().unwrap();
 
@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+const CACHE_FILE_NAME: &str = "workty-graph-cache.json";
+
+/// The graph-distance fields of `WorktreeStatus` - pure functions of
+/// (HEAD oid, main oid, upstream oid), so safe to memoize until one of those changes.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct CachedDistances {
+    pub ahead: Option<usize>,
+    pub behind: Option<usize>,
+    pub behind_main: Option<usize>,
+    pub untracked_commits: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheKey {
+    head: String,
+    main: String,
+    upstream: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    key: CacheKey,
+    distances: CachedDistances,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct GraphCache {
+    // Keyed by worktree path.
+    entries: HashMap<String, CacheEntry>,
+}
+
+impl GraphCache {
+    pub fn load(common_dir: &Path) -> Self {
+        fs::read_to_string(common_dir.join(CACHE_FILE_NAME))
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, common_dir: &Path) {
+        if let Ok(json) = serde_json::to_string(self) {
+            let _ = fs::write(common_dir.join(CACHE_FILE_NAME), json);
+        }
+    }
+
+    /// Returns the cached distances for `worktree_path`, if present and still valid for the
+    /// given (head, main, upstream) oid triple.
+    pub fn get(
+        &self,
+        worktree_path: &str,
+        head: &str,
+        main: &str,
+        upstream: &str,
+    ) -> Option<CachedDistances> {
+        let entry = self.entries.get(worktree_path)?;
+        if entry.key.head == head && entry.key.main == main && entry.key.upstream == upstream {
+            Some(entry.distances)
+        } else {
+            None
+        }
+    }
+
+    pub fn put(
+        &mut self,
+        worktree_path: String,
+        head: String,
+        main: String,
+        upstream: String,
+        distances: CachedDistances,
+    ) {
+        self.entries.insert(
+            worktree_path,
+            CacheEntry {
+                key: CacheKey {
+                    head,
+                    main,
+                    upstream,
+                },
+                distances,
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn distances() -> CachedDistances {
+        CachedDistances {
+            ahead: Some(1),
+            behind: Some(2),
+            behind_main: Some(3),
+            untracked_commits: None,
+        }
+    }
+
+    #[test]
+    fn test_get_hits_on_matching_oid_triple() {
+        let mut cache = GraphCache::default();
+        cache.put(
+            "wt1".to_string(),
+            "head1".to_string(),
+            "main1".to_string(),
+            "upstream1".to_string(),
+            distances(),
+        );
+
+        assert!(cache.get("wt1", "head1", "main1", "upstream1").is_some());
+    }
+
+    #[test]
+    fn test_get_misses_when_any_oid_in_the_triple_changes() {
+        let mut cache = GraphCache::default();
+        cache.put(
+            "wt1".to_string(),
+            "head1".to_string(),
+            "main1".to_string(),
+            "upstream1".to_string(),
+            distances(),
+        );
+
+        assert!(cache.get("wt1", "head2", "main1", "upstream1").is_none());
+        assert!(cache.get("wt1", "head1", "main2", "upstream1").is_none());
+        assert!(cache.get("wt1", "head1", "main1", "upstream2").is_none());
+    }
+
+    #[test]
+    fn test_get_misses_on_unknown_path() {
+        let cache = GraphCache::default();
+        assert!(cache.get("missing", "head1", "main1", "upstream1").is_none());
+    }
+}
@@ -0,0 +1,23 @@
+use serde::Serializer;
+
+/// Serialize a `git2::Oid` as its hex string, the way other git tooling does.
+pub fn serialize<S>(oid: &git2::Oid, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&oid.to_string())
+}
+
+pub mod option {
+    use super::*;
+
+    pub fn serialize<S>(oid: &Option<git2::Oid>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match oid {
+            Some(oid) => serializer.serialize_some(&oid.to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+}
@@ -1,12 +1,25 @@
 use crate::git::GitRepo;
+use crate::graph_cache::{CachedDistances, GraphCache};
+use crate::status_cache::StatusCache;
 use crate::worktree::Worktree;
 use anyhow::Result;
 use rayon::prelude::*;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Default, Serialize)]
+/// Default TTL for the short-lived status cache used by interactive/repeated invocations.
+pub const DEFAULT_STATUS_CACHE_TTL_SECS: u64 = 5;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct WorktreeStatus {
     pub dirty_count: usize,
+    /// Entries staged in the index (INDEX_NEW/MODIFIED/DELETED/RENAMED/TYPECHANGE)
+    pub staged: usize,
+    /// Entries modified in the working tree but not staged
+    pub unstaged: usize,
+    /// Untracked files
+    pub untracked: usize,
+    /// Unmerged (conflicted) paths
+    pub conflicted: usize,
     pub upstream: Option<String>,
     pub ahead: Option<usize>,
     pub behind: Option<usize>,
@@ -18,6 +31,10 @@ pub struct WorktreeStatus {
     pub untracked_commits: Option<usize>,
     /// True if upstream branch has been deleted on remote
     pub upstream_gone: bool,
+    /// Number of stash entries in this worktree
+    pub stash_count: usize,
+    /// `git describe` of HEAD, e.g. "v1.2.0-5-gabc1234"
+    pub describe: Option<String>,
 }
 
 impl WorktreeStatus {
@@ -35,6 +52,11 @@ impl WorktreeStatus {
         self.behind_main.map(|b| b > 0).unwrap_or(false)
     }
 
+    /// Returns true if there are unmerged (conflicted) paths
+    pub fn has_conflicts(&self) -> bool {
+        self.conflicted > 0
+    }
+
     /// Returns true if there are commits that haven't been pushed
     pub fn has_unpushed(&self) -> bool {
         // Commits ahead of upstream
@@ -61,14 +83,14 @@ impl WorktreeStatus {
 
 pub fn get_worktree_status(repo: &GitRepo, worktree: &Worktree) -> WorktreeStatus {
     // Open the worktree repo once and reuse it for all status queries
-    let wt_repo = match git2::Repository::open(&worktree.path) {
+    let mut wt_repo = match git2::Repository::open(&worktree.path) {
         Ok(r) => r,
         Err(_) => {
             return WorktreeStatus::default();
         }
     };
 
-    let dirty_count = get_dirty_count(&wt_repo);
+    let dirty = get_dirty_counts(&wt_repo);
     let (upstream, ahead, behind, upstream_gone) = get_ahead_behind(&wt_repo, worktree);
     let last_commit_time = get_last_commit_time(&wt_repo);
     let behind_main = get_behind_main(&wt_repo, repo);
@@ -77,9 +99,22 @@ pub fn get_worktree_status(repo: &GitRepo, worktree: &Worktree) -> WorktreeStatu
     } else {
         None
     };
+    // Stashes live in the shared common git dir and are identical for every worktree of a
+    // repo - only surface the count on the main worktree's row so it isn't misread as
+    // per-worktree stashed work.
+    let stash_count = if worktree.is_main_worktree(repo) {
+        get_stash_count(&mut wt_repo)
+    } else {
+        0
+    };
+    let describe = get_describe(&wt_repo);
 
     WorktreeStatus {
-        dirty_count,
+        dirty_count: dirty.total,
+        staged: dirty.staged,
+        unstaged: dirty.unstaged,
+        untracked: dirty.untracked,
+        conflicted: dirty.conflicted,
         upstream,
         ahead,
         behind,
@@ -87,10 +122,39 @@ pub fn get_worktree_status(repo: &GitRepo, worktree: &Worktree) -> WorktreeStatu
         behind_main,
         untracked_commits,
         upstream_gone,
+        stash_count,
+        describe,
     }
 }
 
-fn get_dirty_count(repo: &git2::Repository) -> usize {
+#[derive(Debug, Clone, Copy, Default)]
+struct DirtyCounts {
+    staged: usize,
+    unstaged: usize,
+    untracked: usize,
+    conflicted: usize,
+    /// Count of distinct dirty paths. Kept separate from the buckets above because a single
+    /// path can be both staged and further modified (INDEX_MODIFIED + WT_MODIFIED), which would
+    /// double-count it if `staged + unstaged + untracked + conflicted` were used instead.
+    total: usize,
+}
+
+const STAGED_MASK: git2::Status = git2::Status::from_bits_truncate(
+    git2::Status::INDEX_NEW.bits()
+        | git2::Status::INDEX_MODIFIED.bits()
+        | git2::Status::INDEX_DELETED.bits()
+        | git2::Status::INDEX_RENAMED.bits()
+        | git2::Status::INDEX_TYPECHANGE.bits(),
+);
+
+const UNSTAGED_MASK: git2::Status = git2::Status::from_bits_truncate(
+    git2::Status::WT_MODIFIED.bits()
+        | git2::Status::WT_DELETED.bits()
+        | git2::Status::WT_RENAMED.bits()
+        | git2::Status::WT_TYPECHANGE.bits(),
+);
+
+fn get_dirty_counts(repo: &git2::Repository) -> DirtyCounts {
     // Use git2's status API with optimizations for speed
     let mut opts = git2::StatusOptions::new();
     opts.include_untracked(true)
@@ -98,9 +162,35 @@ fn get_dirty_count(repo: &git2::Repository) -> usize {
         .exclude_submodules(true)
         .no_refresh(true); // Don't refresh index from disk
 
-    match repo.statuses(Some(&mut opts)) {
-        Ok(statuses) => statuses.len(),
-        Err(_) => 0,
+    let statuses = match repo.statuses(Some(&mut opts)) {
+        Ok(statuses) => statuses,
+        Err(_) => return DirtyCounts::default(),
+    };
+
+    let mut counts = DirtyCounts::default();
+    for entry in statuses.iter() {
+        classify_status(&mut counts, entry.status());
+    }
+    counts
+}
+
+/// Buckets a single path's `git2::Status` flags into `counts`, incrementing `total` exactly
+/// once per path regardless of how many buckets the path falls into.
+fn classify_status(counts: &mut DirtyCounts, status: git2::Status) {
+    counts.total += 1;
+    if status.intersects(git2::Status::CONFLICTED) {
+        counts.conflicted += 1;
+        return;
+    }
+    if status.intersects(git2::Status::WT_NEW) {
+        counts.untracked += 1;
+        return;
+    }
+    if status.intersects(STAGED_MASK) {
+        counts.staged += 1;
+    }
+    if status.intersects(UNSTAGED_MASK) {
+        counts.unstaged += 1;
     }
 }
 
@@ -171,6 +261,27 @@ fn get_last_commit_time(repo: &git2::Repository) -> Option<i64> {
     Some(now - time.seconds())
 }
 
+fn get_stash_count(repo: &mut git2::Repository) -> usize {
+    let mut count = 0;
+    let _ = repo.stash_foreach(|_, _, _| {
+        count += 1;
+        true
+    });
+    count
+}
+
+fn get_describe(repo: &git2::Repository) -> Option<String> {
+    let mut opts = git2::DescribeOptions::new();
+    opts.describe_tags();
+
+    let describe = repo.describe(&opts).ok()?;
+
+    let mut format_opts = git2::DescribeFormatOptions::new();
+    format_opts.abbreviated_size(7);
+
+    describe.format(Some(&format_opts)).ok()
+}
+
 fn get_behind_main(wt_repo: &git2::Repository, main_repo: &GitRepo) -> Option<usize> {
     // Get HEAD of this worktree
     let head = wt_repo.head().ok()?;
@@ -217,16 +328,99 @@ fn get_untracked_commit_count(wt_repo: &git2::Repository, main_repo: &GitRepo) -
 pub fn get_all_statuses(repo: &GitRepo, worktrees: &[Worktree]) -> Vec<(Worktree, WorktreeStatus)> {
     // Pre-compute main branch OID once for all worktrees
     let main_oid = get_main_branch_oid(repo);
+    let cache = GraphCache::load(&repo.common_dir);
 
-    worktrees
+    let results: Vec<(Worktree, WorktreeStatus, CacheUpdate)> = worktrees
         .par_iter()
         .map(|worktree| {
-            let status = get_worktree_status_full(worktree, main_oid);
-            (worktree.clone(), status)
+            let (status, update) = get_worktree_status_full(repo, worktree, main_oid, &cache);
+            (worktree.clone(), status, update)
         })
+        .collect();
+
+    let mut cache = cache;
+    for (_, _, update) in &results {
+        cache.put(
+            update.path.clone(),
+            update.head.clone(),
+            update.main.clone(),
+            update.upstream.clone(),
+            update.distances,
+        );
+    }
+    cache.save(&repo.common_dir);
+
+    results
+        .into_iter()
+        .map(|(wt, status, _)| (wt, status))
         .collect()
 }
 
+/// Like `get_all_statuses`, but reuses a short-TTL cache keyed on `(worktree path, HEAD oid)`
+/// so repeated interactive invocations (shell prompts, tab-completion, repeated `list`/`pick`)
+/// don't re-walk every worktree when nothing changed. Pass `refresh` to bypass the cache.
+pub fn get_all_statuses_cached(
+    repo: &GitRepo,
+    worktrees: &[Worktree],
+    ttl_secs: u64,
+    refresh: bool,
+) -> Vec<(Worktree, WorktreeStatus)> {
+    if refresh {
+        return get_all_statuses(repo, worktrees);
+    }
+
+    let cache = StatusCache::load(&repo.common_dir);
+    let now = crate::status_cache::now_secs();
+
+    // Keep a slot per input worktree, in the caller's original order, so re-merging cache
+    // hits and recomputed misses doesn't reshuffle `list`/`pick --json` row order depending on
+    // which entries happened to still be warm.
+    let mut slots: Vec<Option<(Worktree, WorktreeStatus)>> = vec![None; worktrees.len()];
+    let mut stale_indices = Vec::new();
+    let mut stale = Vec::new();
+
+    for (i, worktree) in worktrees.iter().enumerate() {
+        let path_key = worktree.path.to_string_lossy().into_owned();
+        match read_head_oid(worktree).and_then(|head| cache.get(&path_key, &head, now, ttl_secs)) {
+            Some(status) => slots[i] = Some((worktree.clone(), status)),
+            None => {
+                stale_indices.push(i);
+                stale.push(worktree.clone());
+            }
+        }
+    }
+
+    if stale.is_empty() {
+        return slots.into_iter().flatten().collect();
+    }
+
+    let recomputed = get_all_statuses(repo, &stale);
+
+    let mut cache = cache;
+    for (worktree, status) in &recomputed {
+        if let Some(head) = read_head_oid(worktree) {
+            cache.put(
+                worktree.path.to_string_lossy().into_owned(),
+                head,
+                now,
+                status.clone(),
+            );
+        }
+    }
+    cache.save(&repo.common_dir);
+
+    for (idx, entry) in stale_indices.into_iter().zip(recomputed) {
+        slots[idx] = Some(entry);
+    }
+
+    slots.into_iter().flatten().collect()
+}
+
+fn read_head_oid(worktree: &Worktree) -> Option<String> {
+    let repo = git2::Repository::open(&worktree.path).ok()?;
+    repo.head().ok()?.target().map(|oid| oid.to_string())
+}
+
 /// Fast version that skips the expensive dirty file check
 pub fn get_all_statuses_fast(
     repo: &GitRepo,
@@ -252,51 +446,169 @@ fn get_main_branch_oid(repo: &GitRepo) -> Option<git2::Oid> {
     reference.target()
 }
 
-fn get_worktree_status_full(worktree: &Worktree, main_oid: Option<git2::Oid>) -> WorktreeStatus {
+/// Resolves the upstream ref for HEAD without doing any graph walk - just ref lookups,
+/// which are cheap enough to redo on every invocation.
+fn resolve_upstream(
+    repo: &git2::Repository,
+    worktree: &Worktree,
+) -> (Option<String>, Option<git2::Oid>, bool) {
+    if worktree.detached {
+        return (None, None, false);
+    }
+
+    let head = match repo.head() {
+        Ok(h) => h,
+        Err(_) => return (None, None, false),
+    };
+
+    if !head.is_branch() {
+        return (None, None, false);
+    }
+
+    let branch_name = match head.shorthand() {
+        Some(name) => name,
+        None => return (None, None, false),
+    };
+
+    let branch = match repo.find_branch(branch_name, git2::BranchType::Local) {
+        Ok(b) => b,
+        Err(_) => return (None, None, false),
+    };
+
+    let upstream_branch = match branch.upstream() {
+        Ok(u) => u,
+        Err(_) => return (None, None, false), // No upstream configured
+    };
+
+    let upstream_name = upstream_branch.name().ok().flatten().map(|s| s.to_string());
+
+    match upstream_branch.get().target() {
+        Some(oid) => (upstream_name, Some(oid), false),
+        // Upstream ref exists but points to nothing - upstream is gone
+        None => (upstream_name, None, true),
+    }
+}
+
+struct CacheUpdate {
+    path: String,
+    head: String,
+    main: String,
+    upstream: String,
+    distances: CachedDistances,
+}
+
+impl CacheUpdate {
+    fn empty(worktree: &Worktree) -> Self {
+        Self {
+            path: worktree.path.to_string_lossy().into_owned(),
+            head: String::new(),
+            main: String::new(),
+            upstream: String::new(),
+            distances: CachedDistances::default(),
+        }
+    }
+}
+
+fn get_worktree_status_full(
+    repo: &GitRepo,
+    worktree: &Worktree,
+    main_oid: Option<git2::Oid>,
+    cache: &GraphCache,
+) -> (WorktreeStatus, CacheUpdate) {
     // Open the worktree repo once and reuse it for all status queries
-    let wt_repo = match git2::Repository::open(&worktree.path) {
+    let mut wt_repo = match git2::Repository::open(&worktree.path) {
         Ok(r) => r,
         Err(_) => {
-            return WorktreeStatus::default();
+            return (WorktreeStatus::default(), CacheUpdate::empty(worktree));
         }
     };
 
-    let dirty_count = get_dirty_count(&wt_repo);
-    let (upstream, ahead, behind, upstream_gone) = get_ahead_behind(&wt_repo, worktree);
+    let dirty = get_dirty_counts(&wt_repo);
     let last_commit_time = get_last_commit_time(&wt_repo);
-
-    // Use pre-computed main_oid for faster calculation
-    let (behind_main, untracked_commits) = if let Some(main_oid) = main_oid {
-        let head_oid = wt_repo.head().ok().and_then(|h| h.target());
-        if let Some(head_oid) = head_oid {
-            let (ahead_of_main, behind_of_main) = wt_repo
-                .graph_ahead_behind(head_oid, main_oid)
-                .unwrap_or((0, 0));
-
-            let untracked = if upstream.is_none() && !worktree.detached {
-                Some(ahead_of_main)
-            } else {
-                None
-            };
-
-            (Some(behind_of_main), untracked)
-        } else {
-            (None, None)
-        }
+    // Stashes live in the shared common git dir and are identical for every worktree of a
+    // repo - only surface the count on the main worktree's row so it isn't misread as
+    // per-worktree stashed work.
+    let stash_count = if worktree.is_main_worktree(repo) {
+        get_stash_count(&mut wt_repo)
     } else {
-        (None, None)
+        0
     };
-
-    WorktreeStatus {
-        dirty_count,
+    let describe = get_describe(&wt_repo);
+
+    let (upstream, upstream_oid, upstream_ref_gone) = resolve_upstream(&wt_repo, worktree);
+    let head_oid = wt_repo.head().ok().and_then(|h| h.target());
+
+    let path_key = worktree.path.to_string_lossy().into_owned();
+    let head_key = head_oid.map(|o| o.to_string()).unwrap_or_default();
+    let main_key = main_oid.map(|o| o.to_string()).unwrap_or_default();
+    let upstream_key = upstream_oid.map(|o| o.to_string()).unwrap_or_default();
+
+    let (distances, upstream_gone) =
+        match cache.get(&path_key, &head_key, &main_key, &upstream_key) {
+            Some(cached) => (cached, upstream_ref_gone),
+            None => {
+                let (ahead, behind, graph_gone) = match (head_oid, upstream_oid) {
+                    (Some(h), Some(u)) => match wt_repo.graph_ahead_behind(h, u) {
+                        Ok((a, b)) => (Some(a), Some(b), false),
+                        Err(_) => (None, None, true),
+                    },
+                    _ => (None, None, false),
+                };
+
+                let (behind_main, untracked_commits) = match (head_oid, main_oid) {
+                    (Some(h), Some(m)) => {
+                        let (ahead_of_main, behind_of_main) =
+                            wt_repo.graph_ahead_behind(h, m).unwrap_or((0, 0));
+
+                        let untracked = if upstream.is_none() && !worktree.detached {
+                            Some(ahead_of_main)
+                        } else {
+                            None
+                        };
+
+                        (Some(behind_of_main), untracked)
+                    }
+                    _ => (None, None),
+                };
+
+                (
+                    CachedDistances {
+                        ahead,
+                        behind,
+                        behind_main,
+                        untracked_commits,
+                    },
+                    graph_gone || upstream_ref_gone,
+                )
+            }
+        };
+
+    let status = WorktreeStatus {
+        dirty_count: dirty.total,
+        staged: dirty.staged,
+        unstaged: dirty.unstaged,
+        untracked: dirty.untracked,
+        conflicted: dirty.conflicted,
         upstream,
-        ahead,
-        behind,
+        ahead: distances.ahead,
+        behind: distances.behind,
         last_commit_time,
-        behind_main,
-        untracked_commits,
+        behind_main: distances.behind_main,
+        untracked_commits: distances.untracked_commits,
         upstream_gone,
-    }
+        stash_count,
+        describe,
+    };
+
+    let update = CacheUpdate {
+        path: path_key,
+        head: head_key,
+        main: main_key,
+        upstream: upstream_key,
+        distances,
+    };
+
+    (status, update)
 }
 
 /// Minimal status - skips expensive dirty check for fast dashboard
@@ -335,6 +647,10 @@ fn get_worktree_status_minimal(worktree: &Worktree, main_oid: Option<git2::Oid>)
 
     WorktreeStatus {
         dirty_count: 0, // Skip dirty check in fast mode
+        staged: 0,
+        unstaged: 0,
+        untracked: 0,
+        conflicted: 0,
         upstream,
         ahead,
         behind,
@@ -342,12 +658,15 @@ fn get_worktree_status_minimal(worktree: &Worktree, main_oid: Option<git2::Oid>)
         behind_main,
         untracked_commits,
         upstream_gone,
+        // Skipped in the fast/minimal path - both are an extra repo walk
+        stash_count: 0,
+        describe: None,
     }
 }
 
 pub fn is_worktree_dirty(worktree: &Worktree) -> bool {
     match git2::Repository::open(&worktree.path) {
-        Ok(repo) => get_dirty_count(&repo) > 0,
+        Ok(repo) => get_dirty_counts(&repo).total > 0,
         Err(_) => false,
     }
 }
@@ -356,3 +675,49 @@ pub fn is_worktree_dirty(worktree: &Worktree) -> bool {
 pub fn check_branch_merged(repo: &GitRepo, branch: &str, base: &str) -> Result<bool> {
     repo.is_merged(branch, base)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_status_buckets_by_kind() {
+        let mut counts = DirtyCounts::default();
+        classify_status(&mut counts, git2::Status::WT_NEW);
+        classify_status(&mut counts, git2::Status::INDEX_MODIFIED);
+        classify_status(&mut counts, git2::Status::CONFLICTED);
+
+        assert_eq!(counts.untracked, 1);
+        assert_eq!(counts.staged, 1);
+        assert_eq!(counts.conflicted, 1);
+        assert_eq!(counts.total, 3);
+    }
+
+    #[test]
+    fn test_classify_status_path_staged_and_modified_counts_once() {
+        // A path can be both staged and further modified (e.g. staged, then edited again).
+        // It should land in both buckets, but `total` must count it as a single dirty path.
+        let mut counts = DirtyCounts::default();
+        classify_status(
+            &mut counts,
+            git2::Status::INDEX_MODIFIED | git2::Status::WT_MODIFIED,
+        );
+
+        assert_eq!(counts.staged, 1);
+        assert_eq!(counts.unstaged, 1);
+        assert_eq!(counts.total, 1);
+    }
+
+    #[test]
+    fn test_classify_status_conflicted_takes_priority() {
+        let mut counts = DirtyCounts::default();
+        classify_status(
+            &mut counts,
+            git2::Status::CONFLICTED | git2::Status::WT_NEW,
+        );
+
+        assert_eq!(counts.conflicted, 1);
+        assert_eq!(counts.untracked, 0);
+        assert_eq!(counts.total, 1);
+    }
+}
@@ -0,0 +1,102 @@
+use crate::status::WorktreeStatus;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+const CACHE_FILE_NAME: &str = "workty-status-cache.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    head: String,
+    cached_at: i64,
+    status: WorktreeStatus,
+}
+
+/// Short-TTL cache of full `WorktreeStatus` results, keyed by worktree path. Unlike
+/// `GraphCache` (which memoizes forever until an oid changes), entries here expire after a
+/// few seconds so a cached run can never be stale for long, even for fields like dirty_count
+/// that change without HEAD moving.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct StatusCache {
+    entries: HashMap<String, CacheEntry>,
+}
+
+impl StatusCache {
+    pub fn load(common_dir: &Path) -> Self {
+        fs::read_to_string(common_dir.join(CACHE_FILE_NAME))
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, common_dir: &Path) {
+        if let Ok(json) = serde_json::to_string(self) {
+            let _ = fs::write(common_dir.join(CACHE_FILE_NAME), json);
+        }
+    }
+
+    pub fn get(&self, path: &str, head: &str, now: i64, ttl_secs: u64) -> Option<WorktreeStatus> {
+        let entry = self.entries.get(path)?;
+        if entry.head != head {
+            return None;
+        }
+        if now - entry.cached_at > ttl_secs as i64 {
+            return None;
+        }
+        Some(entry.status.clone())
+    }
+
+    pub fn put(&mut self, path: String, head: String, cached_at: i64, status: WorktreeStatus) {
+        self.entries.insert(
+            path,
+            CacheEntry {
+                head,
+                cached_at,
+                status,
+            },
+        );
+    }
+}
+
+pub fn now_secs() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_hits_within_ttl_on_matching_head() {
+        let mut cache = StatusCache::default();
+        cache.put("wt1".to_string(), "abc".to_string(), 100, WorktreeStatus::default());
+
+        assert!(cache.get("wt1", "abc", 104, 5).is_some());
+    }
+
+    #[test]
+    fn test_get_misses_once_ttl_elapses() {
+        let mut cache = StatusCache::default();
+        cache.put("wt1".to_string(), "abc".to_string(), 100, WorktreeStatus::default());
+
+        assert!(cache.get("wt1", "abc", 106, 5).is_none());
+    }
+
+    #[test]
+    fn test_get_misses_on_head_mismatch() {
+        let mut cache = StatusCache::default();
+        cache.put("wt1".to_string(), "abc".to_string(), 100, WorktreeStatus::default());
+
+        assert!(cache.get("wt1", "def", 100, 5).is_none());
+    }
+
+    #[test]
+    fn test_get_misses_on_unknown_path() {
+        let cache = StatusCache::default();
+        assert!(cache.get("missing", "abc", 100, 5).is_none());
+    }
+}
@@ -11,6 +11,10 @@ pub struct UiOptions {
     pub color: bool,
     pub ascii: bool,
     pub json: bool,
+    /// Show the full staged/modified/untracked/conflicted breakdown instead of one dirty count
+    pub verbose: bool,
+    /// Bypass the status cache and recompute everything (`--no-cache`/`--refresh`)
+    pub refresh: bool,
 }
 
 impl Default for UiOptions {
@@ -19,6 +23,8 @@ impl Default for UiOptions {
             color: true,
             ascii: false,
             json: false,
+            verbose: false,
+            refresh: false,
         }
     }
 }
@@ -159,6 +165,10 @@ pub fn print_worktree_list(
 }
 
 fn format_dirty(status: &WorktreeStatus, icons: &Icons, opts: &UiOptions) -> String {
+    if opts.verbose {
+        return format_dirty_verbose(status, icons, opts);
+    }
+
     if status.dirty_count > 0 {
         let s = format!("{} {:>3}", icons.dirty, status.dirty_count);
         if opts.color {
@@ -176,6 +186,39 @@ fn format_dirty(status: &WorktreeStatus, icons: &Icons, opts: &UiOptions) -> Str
     }
 }
 
+/// Per-category breakdown, e.g. `+2 ~3 ?1 !1` for 2 staged, 3 modified, 1 untracked, 1 conflicted.
+fn format_dirty_verbose(status: &WorktreeStatus, icons: &Icons, opts: &UiOptions) -> String {
+    if status.dirty_count == 0 {
+        let s = format!("{} {:>3}", icons.clean, "-");
+        return if opts.color { s.green().to_string() } else { s };
+    }
+
+    let mut parts = Vec::new();
+    if status.staged > 0 {
+        parts.push(format!("+{}", status.staged));
+    }
+    if status.unstaged > 0 {
+        parts.push(format!("~{}", status.unstaged));
+    }
+    if status.untracked > 0 {
+        parts.push(format!("?{}", status.untracked));
+    }
+    if status.conflicted > 0 {
+        parts.push(format!("!{}", status.conflicted));
+    }
+
+    let s = parts.join(" ");
+    if opts.color {
+        if status.has_conflicts() {
+            s.red().to_string()
+        } else {
+            s.yellow().to_string()
+        }
+    } else {
+        s
+    }
+}
+
 fn format_sync(status: &WorktreeStatus, icons: &Icons) -> String {
     match (status.ahead, status.behind) {
         (Some(a), Some(b)) => {
@@ -236,30 +279,38 @@ struct RepoInfo {
     common_dir: String,
 }
 
+/// Shared JSON shape for a worktree + its status, used by both `list --json` and
+/// `pick --json` so the two commands return one agreed-upon schema for downstream tools and
+/// editor plugins.
 #[derive(Serialize)]
-struct JsonWorktree {
-    path: String,
-    branch: Option<String>,
-    branch_short: Option<String>,
-    head: String,
-    detached: bool,
-    locked: bool,
-    dirty_count: usize,
-    upstream: Option<String>,
-    ahead: Option<usize>,
-    behind: Option<usize>,
-    last_commit_seconds: Option<i64>,
-    behind_main: Option<usize>,
+pub struct JsonWorktree {
+    pub name: String,
+    pub path: String,
+    pub branch: Option<String>,
+    pub branch_short: Option<String>,
+    pub head: String,
+    pub detached: bool,
+    pub locked: bool,
+    pub dirty_count: usize,
+    pub staged: usize,
+    pub unstaged: usize,
+    pub untracked: usize,
+    pub conflicted: usize,
+    pub upstream: Option<String>,
+    pub upstream_gone: bool,
+    pub ahead: Option<usize>,
+    pub behind: Option<usize>,
+    pub last_commit_seconds: Option<i64>,
+    pub behind_main: Option<usize>,
+    pub untracked_commits: Option<usize>,
+    pub stash_count: usize,
+    pub describe: Option<String>,
 }
 
-fn print_worktree_list_json(
-    repo: &GitRepo,
-    worktrees: &[(Worktree, WorktreeStatus)],
-    current_path: &Path,
-) {
-    let json_worktrees: Vec<JsonWorktree> = worktrees
-        .iter()
-        .map(|(wt, status)| JsonWorktree {
+impl JsonWorktree {
+    pub fn from_status(wt: &Worktree, status: &WorktreeStatus) -> Self {
+        Self {
+            name: wt.name().to_string(),
             path: wt.path.to_string_lossy().into_owned(),
             branch: wt.branch.clone(),
             branch_short: wt.branch_short.clone(),
@@ -267,12 +318,31 @@ fn print_worktree_list_json(
             detached: wt.detached,
             locked: wt.locked,
             dirty_count: status.dirty_count,
+            staged: status.staged,
+            unstaged: status.unstaged,
+            untracked: status.untracked,
+            conflicted: status.conflicted,
             upstream: status.upstream.clone(),
+            upstream_gone: status.upstream_gone,
             ahead: status.ahead,
             behind: status.behind,
             last_commit_seconds: status.last_commit_time,
             behind_main: status.behind_main,
-        })
+            untracked_commits: status.untracked_commits,
+            stash_count: status.stash_count,
+            describe: status.describe.clone(),
+        }
+    }
+}
+
+fn print_worktree_list_json(
+    repo: &GitRepo,
+    worktrees: &[(Worktree, WorktreeStatus)],
+    current_path: &Path,
+) {
+    let json_worktrees: Vec<JsonWorktree> = worktrees
+        .iter()
+        .map(|(wt, status)| JsonWorktree::from_status(wt, status))
         .collect();
 
     let output = JsonOutput {
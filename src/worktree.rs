@@ -1,5 +1,6 @@
 use crate::git::GitRepo;
 use anyhow::{Context, Result};
+use rayon::prelude::*;
 use serde::Serialize;
 use std::path::{Path, PathBuf};
 
@@ -35,12 +36,20 @@ fn check_same_path(p1: &Path, p2: &Path) -> bool {
     p1.canonicalize().ok() == p2.canonicalize().ok()
 }
 
-pub fn list_worktrees(repo: &GitRepo) -> Result<Vec<Worktree>> {
+struct WorktreeEntry {
+    path: PathBuf,
+    should_prune: bool,
+    is_locked: bool,
+}
+
+/// Grabs just the bits that require the shared `Repository` handle - worktree names, their
+/// paths, and the prune/lock flags - then releases the lock so the (much slower) per-worktree
+/// `Repository::open` calls below can run without serializing on it.
+fn enumerate_worktrees(repo: &GitRepo) -> Result<(Vec<WorktreeEntry>, PathBuf)> {
     let git_repo = repo.repo.lock().unwrap();
-    let mut worktrees = Vec::new();
 
-    // 1. Linked Worktrees
     let worktree_names = git_repo.worktrees().context("Failed to list worktrees")?;
+    let mut entries = Vec::new();
     for name in worktree_names.iter() {
         let name = name.unwrap();
         // git2::Worktree structure
@@ -50,51 +59,13 @@ pub fn list_worktrees(repo: &GitRepo) -> Result<Vec<Worktree>> {
         let should_prune = wt.is_prunable(None).unwrap_or(false);
         let is_locked = matches!(wt.is_locked(), Ok(git2::WorktreeLockStatus::Locked(_)));
 
-        // If prunable, we might not be able to open it
-        if should_prune {
-            worktrees.push(Worktree {
-                path,
-                head: String::new(),
-                branch: None,
-                branch_short: None,
-                detached: false,
-                locked: is_locked,
-                prunable: true,
-            });
-            continue;
-        }
-
-        // Try to open repo to get head info
-        // Note: Repository::open on a worktree path opens the worktree context
-        match git2::Repository::open(&path) {
-            Ok(wt_repo) => {
-                let (head, branch, branch_short, detached) = get_repo_head_info(&wt_repo);
-                worktrees.push(Worktree {
-                    path,
-                    head,
-                    branch,
-                    branch_short,
-                    detached,
-                    locked: is_locked,
-                    prunable: false,
-                });
-            }
-            Err(_) => {
-                // Could not open, maybe permissions or broken
-                worktrees.push(Worktree {
-                    path,
-                    head: String::new(),
-                    branch: None,
-                    branch_short: None,
-                    detached: false,
-                    locked: is_locked,
-                    prunable: true, // Treat as broken
-                });
-            }
-        }
+        entries.push(WorktreeEntry {
+            path,
+            should_prune,
+            is_locked,
+        });
     }
 
-    // 2. Main Worktree
     // We need to identify the main worktree.
     // Logic: find common_dir, parent is main worktree.
     let common_dir = if git_repo.is_worktree() {
@@ -108,19 +79,30 @@ pub fn list_worktrees(repo: &GitRepo) -> Result<Vec<Worktree>> {
         // If we are in main, path is .../.git
         git_repo.path()
     };
+    let main_path = common_dir.parent().unwrap_or(common_dir).to_path_buf();
 
-    let main_path = common_dir.parent().unwrap_or(common_dir);
+    Ok((entries, main_path))
+}
 
-    // Add Main Worktree if not already added (though main usually not in linked list)
-    // We open main path to verify and get status
-    if let Ok(main_repo) = git2::Repository::open(main_path) {
-        if !worktrees
-            .iter()
-            .any(|w| check_same_path(&w.path, main_path))
-        {
+pub fn list_worktrees(repo: &GitRepo) -> Result<Vec<Worktree>> {
+    let (entries, main_path) = enumerate_worktrees(repo)?;
+
+    // Each worktree path is an independent repository - open and inspect them in parallel
+    // without holding the shared `GitRepo` mutex.
+    let mut worktrees: Vec<Worktree> = entries
+        .par_iter()
+        .map(|entry| open_worktree_entry(entry))
+        .collect();
+
+    // Add the main worktree if it wasn't already returned as a linked one.
+    if !worktrees
+        .iter()
+        .any(|w| check_same_path(&w.path, &main_path))
+    {
+        if let Ok(main_repo) = git2::Repository::open(&main_path) {
             let (head, branch, branch_short, detached) = get_repo_head_info(&main_repo);
             worktrees.push(Worktree {
-                path: main_path.to_path_buf(),
+                path: main_path,
                 head,
                 branch,
                 branch_short,
@@ -131,9 +113,55 @@ pub fn list_worktrees(repo: &GitRepo) -> Result<Vec<Worktree>> {
         }
     }
 
+    // Thread pool completion order is nondeterministic - sort so table/JSON output is stable.
+    worktrees.sort_by(|a, b| a.path.cmp(&b.path));
+
     Ok(worktrees)
 }
 
+fn open_worktree_entry(entry: &WorktreeEntry) -> Worktree {
+    // If prunable, we might not be able to open it
+    if entry.should_prune {
+        return Worktree {
+            path: entry.path.clone(),
+            head: String::new(),
+            branch: None,
+            branch_short: None,
+            detached: false,
+            locked: entry.is_locked,
+            prunable: true,
+        };
+    }
+
+    // Note: Repository::open on a worktree path opens the worktree context
+    match git2::Repository::open(&entry.path) {
+        Ok(wt_repo) => {
+            let (head, branch, branch_short, detached) = get_repo_head_info(&wt_repo);
+            Worktree {
+                path: entry.path.clone(),
+                head,
+                branch,
+                branch_short,
+                detached,
+                locked: entry.is_locked,
+                prunable: false,
+            }
+        }
+        Err(_) => {
+            // Could not open, maybe permissions or broken
+            Worktree {
+                path: entry.path.clone(),
+                head: String::new(),
+                branch: None,
+                branch_short: None,
+                detached: false,
+                locked: entry.is_locked,
+                prunable: true, // Treat as broken
+            }
+        }
+    }
+}
+
 fn get_repo_head_info(repo: &git2::Repository) -> (String, Option<String>, Option<String>, bool) {
     let head_ref = repo.head();
     match head_ref {